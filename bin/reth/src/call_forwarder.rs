@@ -1,13 +1,10 @@
 use alloy_eips::BlockId;
 use alloy_primitives::{Bytes, U256};
 use alloy_rpc_types_eth::{state::StateOverride, transaction::TransactionRequest, BlockOverrides};
-use jsonrpsee::{
-    http_client::{HttpClient, HttpClientBuilder},
-    proc_macros::rpc,
-    rpc_params,
-    types::{error::INTERNAL_ERROR_CODE, ErrorObject},
-};
-use jsonrpsee_core::{async_trait, client::ClientT, ClientError, RpcResult};
+use jsonrpsee::{proc_macros::rpc, rpc_params};
+use jsonrpsee_core::{async_trait, RpcResult};
+
+use crate::upstream::UpstreamClient;
 
 #[rpc(server, namespace = "eth")]
 pub(crate) trait CallForwarderApi {
@@ -33,14 +30,11 @@ pub(crate) trait CallForwarderApi {
 }
 
 pub(crate) struct CallForwarderExt {
-    client: HttpClient,
+    client: UpstreamClient,
 }
 
 impl CallForwarderExt {
-    pub(crate) fn new(upstream_rpc_url: String) -> Self {
-        let client =
-            HttpClientBuilder::default().build(upstream_rpc_url).expect("Failed to build client");
-
+    pub(crate) const fn new(client: UpstreamClient) -> Self {
         Self { client }
     }
 }
@@ -54,23 +48,12 @@ impl CallForwarderApiServer for CallForwarderExt {
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
     ) -> RpcResult<Bytes> {
-        let result = self
-            .client
-            .clone()
-            .request(
+        self.client
+            .forward(
                 "eth_call",
                 rpc_params![request, block_number, state_overrides, block_overrides],
             )
             .await
-            .map_err(|e| match e {
-                ClientError::Call(e) => e,
-                _ => ErrorObject::owned(
-                    INTERNAL_ERROR_CODE,
-                    format!("Failed to call: {:?}", e),
-                    Some(()),
-                ),
-            })?;
-        Ok(result)
     }
 
     async fn estimate_gas(
@@ -79,19 +62,8 @@ impl CallForwarderApiServer for CallForwarderExt {
         block_number: Option<BlockId>,
         state_override: Option<StateOverride>,
     ) -> RpcResult<U256> {
-        let result = self
-            .client
-            .clone()
-            .request("eth_estimateGas", rpc_params![request, block_number, state_override])
+        self.client
+            .forward("eth_estimateGas", rpc_params![request, block_number, state_override])
             .await
-            .map_err(|e| match e {
-                ClientError::Call(e) => e,
-                _ => ErrorObject::owned(
-                    INTERNAL_ERROR_CODE,
-                    format!("Failed to estimate gas: {:?}", e),
-                    Some(()),
-                ),
-            })?;
-        Ok(result)
     }
 }
@@ -5,18 +5,22 @@ static ALLOC: reth_cli_util::allocator::Allocator = reth_cli_util::allocator::ne
 
 mod block_ingest;
 mod call_forwarder;
+mod nonce_forwarder;
 mod serialized;
 mod spot_meta;
 mod tx_forwarder;
+mod upstream;
 
 use block_ingest::BlockIngest;
 use call_forwarder::CallForwarderApiServer;
-use clap::{Args, Parser};
+use clap::{builder::RangedU64ValueParser, Args, Parser};
+use nonce_forwarder::NonceForwarderApiServer;
 use reth::cli::Cli;
 use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
 use reth_node_ethereum::EthereumNode;
 use tracing::info;
 use tx_forwarder::EthForwarderApiServer;
+use upstream::UpstreamClient;
 
 #[derive(Args, Debug, Clone)]
 struct HyperliquidExtArgs {
@@ -25,8 +29,30 @@ struct HyperliquidExtArgs {
     pub upstream_rpc_url: String,
 
     /// Forward eth_call and eth_estimateGas to the upstream RPC.
+    ///
+    /// Forwarded estimates are returned as is, `--rpc.estimate-gas-buffer` and
+    /// `--rpc.estimate-gas-cap` do not apply to them.
     #[arg(long)]
     pub forward_call: bool,
+
+    /// Add the upstream RPC's pending transactions to eth_getTransactionCount for the `pending`
+    /// tag. Each such query waits for an extra request to the upstream RPC.
+    #[arg(long)]
+    pub forward_pending_nonce: bool,
+
+    /// Maximum number of requests in flight to the upstream RPC. Further requests wait for a
+    /// free slot.
+    #[arg(
+        long,
+        default_value_t = upstream::DEFAULT_MAX_CONCURRENT_REQUESTS,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub upstream_max_concurrent_requests: usize,
+
+    /// Reject transactions that are not signed for this node's chain id, including legacy
+    /// transactions without EIP-155 replay protection, instead of forwarding them upstream.
+    #[arg(long)]
+    pub strict_chain_id: bool,
 }
 
 fn main() {
@@ -44,15 +70,29 @@ fn main() {
             let handle = builder
                 .node(EthereumNode::default())
                 .extend_rpc_modules(move |ctx| {
-                    let upstream_rpc_url = ext_args.upstream_rpc_url;
-                    ctx.modules.replace_configured(
-                        tx_forwarder::EthForwarderExt::new(upstream_rpc_url.clone()).into_rpc(),
-                    )?;
+                    let upstream = UpstreamClient::new(
+                        ext_args.upstream_rpc_url,
+                        ext_args.upstream_max_concurrent_requests,
+                    );
+                    let mut tx_forwarder = tx_forwarder::EthForwarderExt::new(upstream.clone());
+                    if ext_args.strict_chain_id {
+                        tx_forwarder =
+                            tx_forwarder.with_strict_chain_id(ctx.config().chain.chain.id());
+                    }
+                    ctx.modules.replace_configured(tx_forwarder.into_rpc())?;
+                    if ext_args.forward_pending_nonce {
+                        ctx.modules.replace_configured(
+                            nonce_forwarder::NonceForwarderExt::new(
+                                ctx.registry.eth_api().clone(),
+                                upstream.clone(),
+                            )
+                            .into_rpc(),
+                        )?;
+                    }
 
                     if ext_args.forward_call {
                         ctx.modules.replace_configured(
-                            call_forwarder::CallForwarderExt::new(upstream_rpc_url.clone())
-                                .into_rpc(),
+                            call_forwarder::CallForwarderExt::new(upstream).into_rpc(),
                         )?;
                     }
 
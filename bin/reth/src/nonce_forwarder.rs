@@ -0,0 +1,102 @@
+use alloy_eips::BlockId;
+use alloy_primitives::{Address, U256};
+use jsonrpsee::{proc_macros::rpc, rpc_params};
+use jsonrpsee_core::{async_trait, RpcResult};
+use reth_rpc_api::eth::helpers::EthState;
+use tracing::warn;
+
+use crate::upstream::UpstreamClient;
+
+#[rpc(server, namespace = "eth")]
+pub(crate) trait NonceForwarderApi {
+    /// Returns the number of transactions sent from an address at given block number.
+    #[method(name = "getTransactionCount")]
+    async fn transaction_count(
+        &self,
+        address: Address,
+        block_number: Option<BlockId>,
+    ) -> RpcResult<U256>;
+}
+
+/// Serves `eth_getTransactionCount` locally, but asks the upstream RPC for the `pending` tag.
+///
+/// Transactions are forwarded upstream and never enter the local pool, so only the upstream
+/// node knows about a sender's pending transactions until their block is ingested.
+pub(crate) struct NonceForwarderExt<Eth> {
+    eth_api: Eth,
+    client: UpstreamClient,
+}
+
+impl<Eth> NonceForwarderExt<Eth> {
+    pub(crate) const fn new(eth_api: Eth, client: UpstreamClient) -> Self {
+        Self { eth_api, client }
+    }
+}
+
+#[async_trait]
+impl<Eth> NonceForwarderApiServer for NonceForwarderExt<Eth>
+where
+    Eth: EthState + 'static,
+{
+    async fn transaction_count(
+        &self,
+        address: Address,
+        block_number: Option<BlockId>,
+    ) -> RpcResult<U256> {
+        if !is_pending(block_number) {
+            return EthState::transaction_count(&self.eth_api, address, block_number)
+                .await
+                .map_err(Into::into)
+        }
+
+        let (local, upstream) = futures::join!(
+            EthState::transaction_count(&self.eth_api, address, block_number),
+            self.client
+                .request::<U256, _>("eth_getTransactionCount", rpc_params![address, block_number]),
+        );
+        let local = local.map_err(Into::into)?;
+        let upstream = upstream
+            .inspect_err(|err| {
+                warn!(target: "rpc::eth", %address, %err, "Upstream pending nonce unavailable");
+            })
+            .ok();
+        Ok(reported_count(local, upstream))
+    }
+}
+
+/// Returns true if `block_number` is the `pending` tag.
+fn is_pending(block_number: Option<BlockId>) -> bool {
+    block_number.is_some_and(|block_id| block_id.is_pending())
+}
+
+/// Returns the transaction count reported for the `pending` tag.
+///
+/// The upstream node may lag behind the last ingested block, so the larger of both counts is
+/// reported, and the local count is used if the upstream count is unavailable.
+fn reported_count(local: U256, upstream: Option<U256>) -> U256 {
+    upstream.map_or(local, |upstream| local.max(upstream))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_includes_upstream_transactions() {
+        let local = U256::from(5);
+        // two transactions from this sender are pending upstream
+        let upstream = Some(U256::from(7));
+
+        assert_eq!(reported_count(local, upstream), U256::from(7));
+    }
+
+    #[test]
+    fn pending_never_goes_below_local() {
+        let local = U256::from(5);
+
+        // upstream lags behind the last ingested block
+        assert_eq!(reported_count(local, Some(U256::from(3))), local);
+        // upstream is unavailable
+        assert_eq!(reported_count(local, None), local);
+    }
+}
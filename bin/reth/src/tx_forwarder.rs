@@ -1,10 +1,12 @@
-use alloy_primitives::{Bytes, B256};
-use jsonrpsee::{
-    http_client::{HttpClient, HttpClientBuilder},
-    proc_macros::rpc,
-    types::{error::INTERNAL_ERROR_CODE, ErrorObject},
-};
-use jsonrpsee_core::{async_trait, client::ClientT, ClientError, RpcResult};
+use alloy_consensus::Transaction;
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::{Bytes, ChainId, B256};
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee_core::{async_trait, RpcResult};
+use reth_primitives::TransactionSigned;
+use reth_rpc_eth_types::{EthApiError, RpcInvalidTransactionError};
+
+use crate::upstream::UpstreamClient;
 
 #[rpc(server, namespace = "eth")]
 pub(crate) trait EthForwarderApi {
@@ -13,32 +15,88 @@ pub(crate) trait EthForwarderApi {
 }
 
 pub(crate) struct EthForwarderExt {
-    client: HttpClient,
+    client: UpstreamClient,
+    /// If set, transactions not signed for this chain id are rejected before forwarding.
+    strict_chain_id: Option<ChainId>,
 }
 
 impl EthForwarderExt {
-    pub(crate) fn new(upstream_rpc_url: String) -> Self {
-        let client =
-            HttpClientBuilder::default().build(upstream_rpc_url).expect("Failed to build client");
+    pub(crate) const fn new(client: UpstreamClient) -> Self {
+        Self { client, strict_chain_id: None }
+    }
+
+    /// Rejects transactions whose signature is not bound to `chain_id`, including legacy
+    /// transactions without EIP-155 replay protection.
+    pub(crate) const fn with_strict_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.strict_chain_id = Some(chain_id);
+        self
+    }
+}
 
-        Self { client }
+/// Checks that the raw transaction is signed for `chain_id`.
+fn ensure_chain_id(tx: &[u8], chain_id: ChainId) -> Result<(), EthApiError> {
+    let tx = TransactionSigned::decode_2718(&mut &tx[..])
+        .map_err(|_| EthApiError::FailedToDecodeSignedTransaction)?;
+    if tx.chain_id() != Some(chain_id) {
+        return Err(RpcInvalidTransactionError::InvalidChainId.into())
     }
+    Ok(())
 }
 
 #[async_trait]
 impl EthForwarderApiServer for EthForwarderExt {
     async fn send_raw_transaction(&self, tx: Bytes) -> RpcResult<B256> {
-        let txhash =
-            self.client.clone().request("eth_sendRawTransaction", vec![tx]).await.map_err(|e| {
-                match e {
-                    ClientError::Call(e) => e,
-                    _ => ErrorObject::owned(
-                        INTERNAL_ERROR_CODE,
-                        format!("Failed to send transaction: {:?}", e),
-                        Some(()),
-                    ),
-                }
-            })?;
-        Ok(txhash)
+        if let Some(chain_id) = self.strict_chain_id {
+            ensure_chain_id(&tx, chain_id)?;
+        }
+        self.client.forward("eth_sendRawTransaction", vec![tx]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{TxEip1559, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::PrimitiveSignature as Signature;
+
+    const CHAIN_ID: ChainId = 999;
+
+    fn legacy(chain_id: Option<ChainId>) -> Vec<u8> {
+        let tx = TxLegacy { chain_id, ..Default::default() };
+        TransactionSigned::new_unhashed(tx.into(), Signature::test_signature()).encoded_2718()
+    }
+
+    #[test]
+    fn accepts_matching_chain_id() {
+        assert!(ensure_chain_id(&legacy(Some(CHAIN_ID)), CHAIN_ID).is_ok());
+
+        let tx = TxEip1559 { chain_id: CHAIN_ID, ..Default::default() };
+        let tx = TransactionSigned::new_unhashed(tx.into(), Signature::test_signature());
+        assert!(ensure_chain_id(&tx.encoded_2718(), CHAIN_ID).is_ok());
+    }
+
+    #[test]
+    fn rejects_other_chain_id() {
+        assert!(matches!(
+            ensure_chain_id(&legacy(Some(1)), CHAIN_ID),
+            Err(EthApiError::InvalidTransaction(RpcInvalidTransactionError::InvalidChainId))
+        ));
+    }
+
+    #[test]
+    fn rejects_unprotected_legacy() {
+        assert!(matches!(
+            ensure_chain_id(&legacy(None), CHAIN_ID),
+            Err(EthApiError::InvalidTransaction(RpcInvalidTransactionError::InvalidChainId))
+        ));
+    }
+
+    #[test]
+    fn rejects_undecodable() {
+        assert!(matches!(
+            ensure_chain_id(&[0xde, 0xad], CHAIN_ID),
+            Err(EthApiError::FailedToDecodeSignedTransaction)
+        ));
     }
 }
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use jsonrpsee::{
+    http_client::{HttpClient, HttpClientBuilder},
+    types::{error::INTERNAL_ERROR_CODE, ErrorObject, ErrorObjectOwned},
+};
+use jsonrpsee_core::{client::ClientT, traits::ToRpcParams, ClientError, RpcResult};
+use serde::de::DeserializeOwned;
+use tokio::sync::Semaphore;
+
+/// Default maximum number of requests in flight to the upstream RPC.
+pub(crate) const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 64;
+
+/// A client for the upstream RPC that bounds the number of requests in flight.
+///
+/// The client is cheap to clone and all clones share the same limit, so requests forwarded by
+/// different RPC extensions queue behind each other instead of opening more connections.
+#[derive(Debug, Clone)]
+pub(crate) struct UpstreamClient {
+    client: HttpClient,
+    permits: Arc<Semaphore>,
+}
+
+impl UpstreamClient {
+    /// Creates a client allowing `max_concurrent_requests` requests in flight, which must not be
+    /// zero.
+    pub(crate) fn new(upstream_rpc_url: String, max_concurrent_requests: usize) -> Self {
+        let client =
+            HttpClientBuilder::default().build(upstream_rpc_url).expect("Failed to build client");
+
+        Self { client, permits: Arc::new(Semaphore::new(max_concurrent_requests)) }
+    }
+
+    /// Sends a request to the upstream RPC, waiting for a free slot if the limit is reached.
+    pub(crate) async fn request<R, Params>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let _permit = self.permits.acquire().await.expect("semaphore is never closed");
+        self.client.request(method, params).await
+    }
+
+    /// Forwards a request to the upstream RPC and converts failures with
+    /// [`upstream_error`].
+    pub(crate) async fn forward<R, Params>(&self, method: &str, params: Params) -> RpcResult<R>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        self.request(method, params).await.map_err(|err| upstream_error(method, err))
+    }
+}
+
+/// Converts an error returned while forwarding `method` into an error for the caller.
+///
+/// Errors returned by the upstream node itself are passed through unchanged. Anything else,
+/// like a timeout or a rate limited HTTP response, is reported as an internal error whose data
+/// names the forwarded method and carries the underlying error, so callers can tell upstream
+/// connectivity problems apart from execution errors.
+pub(crate) fn upstream_error(method: &str, err: ClientError) -> ErrorObjectOwned {
+    match err {
+        ClientError::Call(err) => err,
+        err => ErrorObject::owned(
+            INTERNAL_ERROR_CODE,
+            format!("Failed to forward {method} to upstream: {err}"),
+            Some(serde_json::json!({ "method": method, "error": err.to_string() })),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{rpc_params, server::Server, RpcModule};
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    #[tokio::test]
+    async fn clones_share_limit() {
+        let client = UpstreamClient::new("http://localhost:8545".to_string(), 2);
+        let other = client.clone();
+
+        let _permit = client.permits.acquire().await.unwrap();
+        assert_eq!(other.permits.available_permits(), 1);
+    }
+
+    #[test]
+    fn upstream_call_error_is_passed_through() {
+        let err = ErrorObject::owned(3, "execution reverted", Some("0x"));
+        assert_eq!(upstream_error("eth_call", ClientError::Call(err.clone())), err);
+    }
+
+    #[test]
+    fn transport_error_names_method() {
+        let err = upstream_error("eth_estimateGas", ClientError::RequestTimeout);
+        assert_eq!(err.code(), INTERNAL_ERROR_CODE);
+        let data: serde_json::Value = serde_json::from_str(err.data().unwrap().get()).unwrap();
+        assert_eq!(data["method"], "eth_estimateGas");
+        assert_eq!(data["error"], ClientError::RequestTimeout.to_string());
+    }
+
+    #[tokio::test]
+    async fn requests_in_flight_never_exceed_limit() {
+        #[derive(Default)]
+        struct Load {
+            current: AtomicUsize,
+            peak: AtomicUsize,
+        }
+
+        let load = Arc::new(Load::default());
+        let mut module = RpcModule::new(load.clone());
+        module
+            .register_async_method("slow", |_, load, _| async move {
+                let current = load.current.fetch_add(1, Ordering::SeqCst) + 1;
+                load.peak.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                load.current.fetch_sub(1, Ordering::SeqCst);
+                0u64
+            })
+            .unwrap();
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        let handle = server.start(module);
+
+        let client = UpstreamClient::new(format!("http://{addr}"), 3);
+        let requests = (0..12).map(|_| client.request::<u64, _>("slow", rpc_params![]));
+        for res in futures::future::join_all(requests).await {
+            res.unwrap();
+        }
+        assert_eq!(load.peak.load(Ordering::SeqCst), 3);
+
+        handle.stop().unwrap();
+    }
+}
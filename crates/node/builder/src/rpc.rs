@@ -480,8 +480,9 @@ where
 
         ext(ctx.modules, ctx.auth_module)?;
         extend_rpc_modules.extend_rpc_modules(ctx)?;
+        modules.retain_methods(|method| config.rpc.is_rpc_method_allowed(method));
 
-        let server_config = config.rpc.rpc_server_config();
+        let server_config = config.rpc.rpc_server_config()?;
         let cloned_modules = modules.clone();
         let launch_rpc = server_config.start(&cloned_modules).map_ok(|handle| {
             if let Some(path) = handle.ipc_endpoint() {
@@ -25,17 +25,22 @@ pub struct GasPriceOracleArgs {
     /// The percentile of gas prices to use for the estimate
     #[arg(long = "gpo.percentile", default_value_t = DEFAULT_GAS_PRICE_PERCENTILE)]
     pub percentile: u32,
+
+    /// The default gas price to suggest when there are not enough recent blocks with transactions
+    #[arg(long = "gpo.default")]
+    pub default_suggested_fee: Option<u64>,
 }
 
 impl GasPriceOracleArgs {
     /// Returns a [`GasPriceOracleConfig`] from the arguments.
     pub fn gas_price_oracle_config(&self) -> GasPriceOracleConfig {
-        let Self { blocks, ignore_price, max_price, percentile } = self;
+        let Self { blocks, ignore_price, max_price, percentile, default_suggested_fee } = self;
         GasPriceOracleConfig {
             max_price: Some(U256::from(*max_price)),
             ignore_price: Some(U256::from(*ignore_price)),
             percentile: *percentile,
             blocks: *blocks,
+            default: default_suggested_fee.map(U256::from),
             ..Default::default()
         }
     }
@@ -48,6 +53,7 @@ impl Default for GasPriceOracleArgs {
             ignore_price: DEFAULT_IGNORE_GAS_PRICE.to(),
             max_price: DEFAULT_MAX_GAS_PRICE.to(),
             percentile: DEFAULT_GAS_PRICE_PERCENTILE,
+            default_suggested_fee: None,
         }
     }
 }
@@ -73,10 +79,19 @@ mod tests {
                 ignore_price: DEFAULT_IGNORE_GAS_PRICE.to(),
                 max_price: DEFAULT_MAX_GAS_PRICE.to(),
                 percentile: DEFAULT_GAS_PRICE_PERCENTILE,
+                default_suggested_fee: None,
             }
         );
     }
 
+    #[test]
+    fn test_parse_gpo_default() {
+        let args =
+            CommandParser::<GasPriceOracleArgs>::parse_from(["reth", "--gpo.default", "1000"]).args;
+        assert_eq!(args.default_suggested_fee, Some(1000));
+        assert_eq!(args.gas_price_oracle_config().default, Some(U256::from(1000)));
+    }
+
     #[test]
     fn gpo_args_default_sanity_test() {
         let default_args = GasPriceOracleArgs::default();
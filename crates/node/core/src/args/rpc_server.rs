@@ -24,6 +24,9 @@ use crate::args::{
 /// Default max number of subscriptions per connection.
 pub(crate) const RPC_DEFAULT_MAX_SUBS_PER_CONN: u32 = 1024;
 
+/// Default number of messages buffered per connection.
+pub(crate) const RPC_DEFAULT_MESSAGE_BUFFER_CAPACITY: u32 = 1024;
+
 /// Default max request size in MB.
 pub(crate) const RPC_DEFAULT_MAX_REQUEST_SIZE_MB: u32 = 15;
 
@@ -79,6 +82,30 @@ pub struct RpcServerArgs {
     #[arg(long = "ws.api", value_parser = RpcModuleSelectionValueParser::default())]
     pub ws_api: Option<RpcModuleSelection>,
 
+    /// Interval in seconds at which the WS server pings its clients.
+    ///
+    /// Pings are disabled unless this or `--ws.idle-timeout` is set, and default to half the
+    /// timeout if only the timeout is given.
+    #[arg(
+        long = "ws.ping-interval",
+        value_name = "SECONDS",
+        value_parser = RangedU64ValueParser::<u64>::new().range(1..)
+    )]
+    pub ws_ping_interval: Option<u64>,
+
+    /// Close WS connections that have not answered a ping for this many seconds.
+    ///
+    /// Must be greater than `--ws.ping-interval`, and defaults to twice the interval if only the
+    /// interval is given. Inactivity is checked at each ping, so a connection is closed at the
+    /// first ping after the timeout has passed. WS clients answer pings on their own, so a live
+    /// client with only idle subscriptions stays connected.
+    #[arg(
+        long = "ws.idle-timeout",
+        value_name = "SECONDS",
+        value_parser = RangedU64ValueParser::<u64>::new().range(1..)
+    )]
+    pub ws_idle_timeout: Option<u64>,
+
     /// Disable the IPC-RPC server
     #[arg(long)]
     pub ipcdisable: bool,
@@ -132,6 +159,27 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.max-subscriptions-per-connection", alias = "rpc-max-subscriptions-per-connection", default_value_t = RPC_DEFAULT_MAX_SUBS_PER_CONN.into())]
     pub rpc_max_subscriptions_per_connection: MaxU32,
 
+    /// Maximum number of responses and subscription notifications buffered per connection.
+    #[arg(
+        long = "rpc.message-buffer-capacity",
+        value_name = "MESSAGES",
+        value_parser = RangedU64ValueParser::<u32>::new().range(1..),
+        default_value_t = RPC_DEFAULT_MESSAGE_BUFFER_CAPACITY
+    )]
+    pub rpc_message_buffer_capacity: u32,
+
+    /// Close a subscription with an error notification if its next notification finds no room
+    /// in the connection's message buffer for this many seconds.
+    ///
+    /// By default a slow subscriber holds back its notifications for as long as it stays
+    /// connected.
+    #[arg(
+        long = "rpc.slow-subscriber-timeout",
+        value_name = "SECONDS",
+        value_parser = RangedU64ValueParser::<u64>::new().range(1..)
+    )]
+    pub rpc_slow_subscriber_timeout: Option<u64>,
+
     /// Maximum number of RPC server connections.
     #[arg(long = "rpc.max-connections", alias = "rpc-max-connections", value_name = "COUNT", default_value_t = RPC_DEFAULT_MAX_CONNECTIONS.into())]
     pub rpc_max_connections: MaxU32,
@@ -171,6 +219,27 @@ pub struct RpcServerArgs {
     )]
     pub rpc_max_simulate_blocks: u64,
 
+    /// Percentage added on top of `eth_estimateGas` results.
+    ///
+    /// A buffer protects transactions whose gas usage depends on state that may change before
+    /// they are included, at the cost of reserving more gas than needed. The buffered estimate
+    /// never exceeds the block gas limit, `--rpc.estimate-gas-cap` or what the caller can afford.
+    /// Plain transfers to accounts without code are not buffered, as they always use 21000 gas.
+    /// Set to 0 to return the raw estimate. Has no effect if `eth_estimateGas` is forwarded to
+    /// another node.
+    #[arg(long = "rpc.estimate-gas-buffer", value_name = "PERCENT", default_value_t = 0)]
+    pub rpc_estimate_gas_buffer: u64,
+
+    /// Maximum gas limit tried by the binary search of `eth_estimateGas`.
+    ///
+    /// Transactions that need more gas fail to estimate. Defaults to the block gas limit.
+    #[arg(
+        long = "rpc.estimate-gas-cap",
+        value_name = "GAS",
+        value_parser = RangedU64ValueParser::<u64>::new().range(1..)
+    )]
+    pub rpc_estimate_gas_cap: Option<u64>,
+
     /// The maximum proof window for historical proof generation.
     /// This value allows for generating historical proofs up to
     /// configured number of blocks from current tip (up to `tip - window`).
@@ -185,6 +254,34 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.proof-permits", alias = "rpc-proof-permits", value_name = "COUNT", default_value_t = constants::DEFAULT_PROOF_PERMITS)]
     pub rpc_proof_permits: usize,
 
+    /// Log a warning for RPC requests that take longer than the given number of milliseconds.
+    #[arg(long = "rpc.slow-query-ms", value_name = "MS")]
+    pub rpc_slow_query_ms: Option<u64>,
+
+    /// Only serve the RPC methods matching one of these comma separated patterns.
+    ///
+    /// A pattern is either a full method name or a prefix followed by `*`, e.g. `eth_*`.
+    /// Whitespace around a pattern is ignored.
+    #[arg(
+        long = "rpc.methods-allow",
+        value_name = "PATTERNS",
+        value_delimiter = ',',
+        value_parser = parse_rpc_method_pattern
+    )]
+    pub rpc_methods_allow: Vec<String>,
+
+    /// Do not serve the RPC methods matching one of these comma separated patterns.
+    ///
+    /// Takes precedence over `--rpc.methods-allow`. Blocked methods are removed before the
+    /// server starts and return method-not-found.
+    #[arg(
+        long = "rpc.methods-deny",
+        value_name = "PATTERNS",
+        value_delimiter = ',',
+        value_parser = parse_rpc_method_pattern
+    )]
+    pub rpc_methods_deny: Vec<String>,
+
     /// Path to file containing disallowed addresses, json-encoded list of strings. Block
     /// validation API will reject blocks containing transactions from these addresses.
     #[arg(long = "builder.disallow", value_name = "PATH", value_parser = reth_cli_util::parsers::read_json_from_file::<HashSet<Address>>)]
@@ -310,6 +407,8 @@ impl Default for RpcServerArgs {
             ws_port: constants::DEFAULT_WS_RPC_PORT,
             ws_allowed_origins: None,
             ws_api: None,
+            ws_ping_interval: None,
+            ws_idle_timeout: None,
             ipcdisable: false,
             ipcpath: constants::DEFAULT_IPC_ENDPOINT.to_string(),
             auth_addr: Ipv4Addr::LOCALHOST.into(),
@@ -321,16 +420,23 @@ impl Default for RpcServerArgs {
             rpc_max_request_size: RPC_DEFAULT_MAX_REQUEST_SIZE_MB.into(),
             rpc_max_response_size: RPC_DEFAULT_MAX_RESPONSE_SIZE_MB.into(),
             rpc_max_subscriptions_per_connection: RPC_DEFAULT_MAX_SUBS_PER_CONN.into(),
+            rpc_message_buffer_capacity: RPC_DEFAULT_MESSAGE_BUFFER_CAPACITY,
+            rpc_slow_subscriber_timeout: None,
             rpc_max_connections: RPC_DEFAULT_MAX_CONNECTIONS.into(),
             rpc_max_tracing_requests: constants::default_max_tracing_requests(),
             rpc_max_blocks_per_filter: constants::DEFAULT_MAX_BLOCKS_PER_FILTER.into(),
             rpc_max_logs_per_response: (constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64).into(),
             rpc_gas_cap: constants::gas_oracle::RPC_DEFAULT_GAS_CAP,
             rpc_max_simulate_blocks: constants::DEFAULT_MAX_SIMULATE_BLOCKS,
+            rpc_estimate_gas_buffer: 0,
+            rpc_estimate_gas_cap: None,
             rpc_eth_proof_window: constants::DEFAULT_ETH_PROOF_WINDOW,
             gas_price_oracle: GasPriceOracleArgs::default(),
             rpc_state_cache: RpcStateCacheArgs::default(),
             rpc_proof_permits: constants::DEFAULT_PROOF_PERMITS,
+            rpc_slow_query_ms: None,
+            rpc_methods_allow: Vec::new(),
+            rpc_methods_deny: Vec::new(),
             builder_disallow: Default::default(),
         }
     }
@@ -341,6 +447,22 @@ impl Default for RpcServerArgs {
 #[non_exhaustive]
 struct RpcModuleSelectionValueParser;
 
+/// Parses a `--rpc.methods-allow` or `--rpc.methods-deny` pattern, ignoring surrounding
+/// whitespace.
+fn parse_rpc_method_pattern(pattern: &str) -> Result<String, String> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return Err("empty method pattern".to_string())
+    }
+    let name = pattern.strip_suffix('*').unwrap_or(pattern);
+    if name.contains(|c: char| c.is_whitespace() || c == '*') {
+        return Err(format!(
+            "invalid method pattern '{pattern}': expected a method name, optionally followed by `*`"
+        ))
+    }
+    Ok(pattern.to_string())
+}
+
 impl TypedValueParser for RpcModuleSelectionValueParser {
     type Value = RpcModuleSelection;
 
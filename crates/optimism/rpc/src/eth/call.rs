@@ -25,6 +25,15 @@ where
     Self::Error: From<OpEthApiError>,
     N: OpNodeCore,
 {
+    #[inline]
+    fn estimate_gas_buffer_percent(&self) -> u64 {
+        self.inner.eth_api.estimate_gas_buffer_percent()
+    }
+
+    #[inline]
+    fn estimate_gas_cap(&self) -> Option<u64> {
+        self.inner.eth_api.estimate_gas_cap()
+    }
 }
 
 impl<N> Call for OpEthApi<N>
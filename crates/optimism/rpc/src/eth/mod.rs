@@ -341,6 +341,8 @@ impl OpEthApiBuilder {
             ctx.new_gas_price_oracle(),
             ctx.config.rpc_gas_cap,
             ctx.config.rpc_max_simulate_blocks,
+            ctx.config.estimate_gas_buffer_percent,
+            ctx.config.estimate_gas_cap,
             ctx.config.eth_proof_window,
             blocking_task_pool,
             ctx.new_fee_history_cache(),
@@ -44,7 +44,7 @@ pub mod servers {
         miner::MinerApiServer,
         net::NetApiServer,
         otterscan::OtterscanServer,
-        reth::RethApiServer,
+        reth::{RethApiServer, RethPubSubApiServer},
         rpc::RpcApiServer,
         trace::TraceApiServer,
         txpool::TxPoolApiServer,
@@ -1,5 +1,5 @@
 use alloy_eips::BlockId;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, B256, U256};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use std::collections::HashMap;
 
@@ -14,3 +14,23 @@ pub trait RethApi {
         block_id: BlockId,
     ) -> RpcResult<HashMap<Address, U256>>;
 }
+
+/// Reth pub-sub rpc interface.
+#[rpc(server, namespace = "reth")]
+pub trait RethPubSubApi {
+    /// Creates a subscription that yields the storage slots of `address` changed by each new
+    /// canonical block, mapped to their value at the end of that block.
+    ///
+    /// Blocks that don't change the account's storage produce no notification. On a reorg, the
+    /// slots changed by the reverted blocks are first announced with their value before those
+    /// blocks.
+    #[subscription(
+        name = "subscribeStorageChanges" => "storageChanges",
+        unsubscribe = "unsubscribeStorageChanges",
+        item = HashMap<B256, B256>
+    )]
+    async fn reth_subscribe_storage_changes(
+        &self,
+        address: Address,
+    ) -> jsonrpsee::core::SubscriptionResult;
+}
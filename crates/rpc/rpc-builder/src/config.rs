@@ -1,6 +1,6 @@
-use std::{net::SocketAddr, path::PathBuf};
+use std::{net::SocketAddr, path::PathBuf, time::Duration};
 
-use jsonrpsee::server::ServerBuilder;
+use jsonrpsee::server::{PingConfig, ServerBuilder};
 use reth_node_core::{args::RpcServerArgs, utils::get_or_create_jwt_secret_from_path};
 use reth_rpc::ValidationApiConfig;
 use reth_rpc_eth_types::{EthConfig, EthStateCacheConfig, GasPriceOracleConfig};
@@ -50,13 +50,22 @@ pub trait RethRpcServerConfig {
     fn transport_rpc_module_config(&self) -> TransportRpcModuleConfig;
 
     /// Returns the default server builder for http/ws
-    fn http_ws_server_builder(&self) -> ServerBuilder<Identity, Identity>;
+    fn http_ws_server_builder(&self) -> Result<ServerBuilder<Identity, Identity>, RpcError>;
 
     /// Returns the default ipc server builder
     fn ipc_server_builder(&self) -> IpcServerBuilder<Identity, Identity>;
 
+    /// Returns the WS ping configuration, if pings are enabled.
+    ///
+    /// Fails if the idle timeout does not exceed the ping interval, since an idle client only
+    /// proves it is alive by answering a ping.
+    fn ws_ping_config(&self) -> Result<Option<PingConfig>, RpcError>;
+
     /// Creates the [`RpcServerConfig`] from cli args.
-    fn rpc_server_config(&self) -> RpcServerConfig;
+    fn rpc_server_config(&self) -> Result<RpcServerConfig, RpcError>;
+
+    /// Returns whether the given method passes the configured method allow and deny lists.
+    fn is_rpc_method_allowed(&self, method: &str) -> bool;
 
     /// Creates the [`AuthServerConfig`] from cli args.
     fn auth_server_config(&self, jwt_secret: JwtSecret) -> Result<AuthServerConfig, RpcError>;
@@ -100,6 +109,9 @@ impl RethRpcServerConfig for RpcServerArgs {
             .eth_proof_window(self.rpc_eth_proof_window)
             .rpc_gas_cap(self.rpc_gas_cap)
             .rpc_max_simulate_blocks(self.rpc_max_simulate_blocks)
+            .estimate_gas_buffer_percent(self.rpc_estimate_gas_buffer)
+            .estimate_gas_cap(self.rpc_estimate_gas_cap)
+            .slow_subscriber_timeout(self.rpc_slow_subscriber_timeout.map(Duration::from_secs))
             .state_cache(self.state_cache_config())
             .gpo_config(self.gas_price_oracle_config())
             .proof_permits(self.rpc_proof_permits)
@@ -157,15 +169,22 @@ impl RethRpcServerConfig for RpcServerArgs {
             config = config.with_ipc(RpcModuleSelection::default_ipc_modules());
         }
 
-        config
+        Ok(config)
     }
 
-    fn http_ws_server_builder(&self) -> ServerBuilder<Identity, Identity> {
-        ServerBuilder::new()
+    fn http_ws_server_builder(&self) -> Result<ServerBuilder<Identity, Identity>, RpcError> {
+        let builder = ServerBuilder::new()
             .max_connections(self.rpc_max_connections.get())
             .max_request_body_size(self.rpc_max_request_size_bytes())
             .max_response_body_size(self.rpc_max_response_size_bytes())
             .max_subscriptions_per_connection(self.rpc_max_subscriptions_per_connection.get())
+            .set_message_buffer_capacity(self.rpc_message_buffer_capacity);
+
+        // pings only apply to WS connections, including those on a shared http/ws port
+        Ok(match self.ws_ping_config()? {
+            Some(ping_config) => builder.enable_ws_ping(ping_config),
+            None => builder,
+        })
     }
 
     fn ipc_server_builder(&self) -> IpcServerBuilder<Identity, Identity> {
@@ -174,10 +193,20 @@ impl RethRpcServerConfig for RpcServerArgs {
             .max_request_body_size(self.rpc_max_request_size_bytes())
             .max_response_body_size(self.rpc_max_response_size_bytes())
             .max_connections(self.rpc_max_connections.get())
+            .set_message_buffer_capacity(self.rpc_message_buffer_capacity)
+    }
+
+    fn ws_ping_config(&self) -> Result<Option<PingConfig>, RpcError> {
+        let timings = ws_ping_timings(self.ws_ping_interval, self.ws_idle_timeout)?;
+        Ok(timings.map(|(interval, timeout)| {
+            PingConfig::new().ping_interval(interval).inactive_limit(timeout)
+        }))
     }
 
-    fn rpc_server_config(&self) -> RpcServerConfig {
-        let mut config = RpcServerConfig::default().with_jwt_secret(self.rpc_secret_key());
+    fn rpc_server_config(&self) -> Result<RpcServerConfig, RpcError> {
+        let mut config = RpcServerConfig::default()
+            .with_jwt_secret(self.rpc_secret_key())
+            .with_slow_request_threshold(self.rpc_slow_query_ms.map(Duration::from_millis));
 
         if self.http_api.is_some() && !self.http {
             warn!(
@@ -190,14 +219,14 @@ impl RethRpcServerConfig for RpcServerArgs {
             let socket_address = SocketAddr::new(self.http_addr, self.http_port);
             config = config
                 .with_http_address(socket_address)
-                .with_http(self.http_ws_server_builder())
+                .with_http(self.http_ws_server_builder()?)
                 .with_http_cors(self.http_corsdomain.clone())
                 .with_ws_cors(self.ws_allowed_origins.clone());
         }
 
         if self.ws {
             let socket_address = SocketAddr::new(self.ws_addr, self.ws_port);
-            config = config.with_ws_address(socket_address).with_ws(self.http_ws_server_builder());
+            config = config.with_ws_address(socket_address).with_ws(self.http_ws_server_builder()?);
         }
 
         if self.is_ipc_enabled() {
@@ -208,6 +237,16 @@ impl RethRpcServerConfig for RpcServerArgs {
         config
     }
 
+    fn is_rpc_method_allowed(&self, method: &str) -> bool {
+        let matches = |pattern: &String| match pattern.strip_suffix('*') {
+            Some(prefix) => method.starts_with(prefix),
+            None => method == pattern.as_str(),
+        };
+
+        (self.rpc_methods_allow.is_empty() || self.rpc_methods_allow.iter().any(matches)) &&
+            !self.rpc_methods_deny.iter().any(matches)
+    }
+
     fn auth_server_config(&self, jwt_secret: JwtSecret) -> Result<AuthServerConfig, RpcError> {
         let address = SocketAddr::new(self.auth_addr, self.auth_port);
 
@@ -235,6 +274,29 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 }
 
+/// Returns the WS ping interval and idle timeout for the given flags, if pings are enabled.
+///
+/// A missing timeout is twice the interval and a missing interval is half the timeout, so an idle
+/// client gets two chances to answer a ping before it is closed.
+fn ws_ping_timings(
+    interval: Option<u64>,
+    timeout: Option<u64>,
+) -> Result<Option<(Duration, Duration)>, RpcError> {
+    let (interval, timeout) =
+        match (interval.map(Duration::from_secs), timeout.map(Duration::from_secs)) {
+            (None, None) => return Ok(None),
+            (Some(interval), None) => (interval, interval * 2),
+            (None, Some(timeout)) => (timeout / 2, timeout),
+            (Some(interval), Some(timeout)) => (interval, timeout),
+        };
+    if timeout <= interval {
+        return Err(RpcError::Custom(format!(
+            "--ws.idle-timeout ({timeout:?}) must be greater than --ws.ping-interval ({interval:?})"
+        )))
+    }
+    Ok(Some((interval, timeout)))
+}
+
 #[cfg(test)]
 mod tests {
     use clap::{Args, Parser};
@@ -267,6 +329,93 @@ mod tests {
         assert!(args.is_err());
     }
 
+    #[test]
+    fn test_ws_ping_config() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert!(args.ws_ping_config().unwrap().is_none());
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--ws.idle-timeout", "60"]).args;
+        assert!(args.ws_ping_config().unwrap().is_some());
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--ws.ping-interval",
+            "30",
+            "--ws.idle-timeout",
+            "30",
+        ])
+        .args;
+        assert!(args.ws_ping_config().is_err());
+        assert!(args.rpc_server_config().is_err());
+
+        let args =
+            CommandParser::<RpcServerArgs>::try_parse_from(["reth", "--ws.ping-interval", "0"]);
+        assert!(args.is_err());
+    }
+
+    #[test]
+    fn test_ws_ping_timings() {
+        let secs = Duration::from_secs;
+        assert!(ws_ping_timings(None, None).unwrap().is_none());
+
+        // an interval beyond jsonrpsee's default 40s timeout still leaves time for the pong
+        assert_eq!(ws_ping_timings(Some(60), None).unwrap(), Some((secs(60), secs(120))));
+        // a timeout below jsonrpsee's default 30s interval still pings in time
+        assert_eq!(ws_ping_timings(None, Some(20)).unwrap(), Some((secs(10), secs(20))));
+        assert_eq!(
+            ws_ping_timings(None, Some(1)).unwrap(),
+            Some((Duration::from_millis(500), secs(1)))
+        );
+
+        assert_eq!(ws_ping_timings(Some(10), Some(15)).unwrap(), Some((secs(10), secs(15))));
+        assert!(ws_ping_timings(Some(10), Some(10)).is_err());
+        assert!(ws_ping_timings(Some(10), Some(5)).is_err());
+    }
+
+    #[test]
+    fn test_rpc_method_filter() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert!(args.is_rpc_method_allowed("anvil_setBalance"));
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.methods-deny", "anvil_*"])
+                .args;
+        assert!(!args.is_rpc_method_allowed("anvil_setBalance"));
+        assert!(args.is_rpc_method_allowed("eth_blockNumber"));
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.methods-allow",
+            "eth_*,net_version",
+            "--rpc.methods-deny",
+            "eth_sendRawTransaction",
+        ])
+        .args;
+        assert!(args.is_rpc_method_allowed("eth_blockNumber"));
+        assert!(args.is_rpc_method_allowed("net_version"));
+        assert!(!args.is_rpc_method_allowed("net_peerCount"));
+        assert!(!args.is_rpc_method_allowed("eth_sendRawTransaction"));
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.methods-allow",
+            "eth_call, eth_blockNumber",
+        ])
+        .args;
+        assert!(args.is_rpc_method_allowed("eth_call"));
+        assert!(args.is_rpc_method_allowed("eth_blockNumber"));
+
+        for patterns in ["eth_call,", "eth_call,,net_version", "eth call", "eth_*_call", "*eth"] {
+            let args = CommandParser::<RpcServerArgs>::try_parse_from([
+                "reth",
+                "--rpc.methods-deny",
+                patterns,
+            ]);
+            assert!(args.is_err(), "{patterns}");
+        }
+    }
+
     #[test]
     fn test_transport_rpc_module_config() {
         let args = CommandParser::<RpcServerArgs>::parse_from([
@@ -338,7 +487,7 @@ mod tests {
             "8888",
         ])
         .args;
-        let config = args.rpc_server_config();
+        let config = args.rpc_server_config().unwrap();
         assert_eq!(
             config.http_address().unwrap(),
             SocketAddr::V4(SocketAddrV4::new(
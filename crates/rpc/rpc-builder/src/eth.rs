@@ -71,7 +71,8 @@ where
         let filter =
             EthFilter::new(api.clone(), ctx.config.filter_config(), Box::new(ctx.executor.clone()));
 
-        let pubsub = EthPubSub::with_spawner(api.clone(), Box::new(ctx.executor.clone()));
+        let pubsub = EthPubSub::with_spawner(api.clone(), Box::new(ctx.executor.clone()))
+            .with_slow_subscriber_timeout(ctx.config.slow_subscriber_timeout);
 
         Self { api, cache: ctx.cache, filter, pubsub }
     }
@@ -845,6 +845,8 @@ pub struct RpcRegistryInner<
     eth: EthHandlers<Provider, EthApi>,
     /// to put trace calls behind semaphore
     blocking_pool_guard: BlockingTaskGuard,
+    /// How long a subscription may stall on a full message buffer before it is closed
+    slow_subscriber_timeout: Option<Duration>,
     /// Contains the [Methods] of a module
     modules: HashMap<RethRpcModule, Methods>,
 }
@@ -884,6 +886,7 @@ where
         EvmConfig: ConfigureEvm<Header = Provider::Header>,
     {
         let blocking_pool_guard = BlockingTaskGuard::new(config.eth.max_tracing_requests);
+        let slow_subscriber_timeout = config.eth.slow_subscriber_timeout;
 
         let eth = EthHandlers::bootstrap(
             provider.clone(),
@@ -904,6 +907,7 @@ where
             consensus,
             modules: Default::default(),
             blocking_pool_guard,
+            slow_subscriber_timeout,
             block_executor,
         }
     }
@@ -1093,9 +1097,14 @@ where
     /// # Panics
     ///
     /// If called outside of the tokio runtime.
-    pub fn register_reth(&mut self) -> &mut Self {
+    pub fn register_reth(&mut self) -> &mut Self
+    where
+        Provider: CanonStateSubscriptions,
+    {
         let rethapi = self.reth_api();
-        self.modules.insert(RethRpcModule::Reth, rethapi.into_rpc().into());
+        let mut module = RethApiServer::into_rpc(rethapi.clone());
+        module.merge(RethPubSubApiServer::into_rpc(rethapi)).expect("No conflicts");
+        self.modules.insert(RethRpcModule::Reth, module.into());
         self
     }
 
@@ -1177,6 +1186,7 @@ where
     /// Instantiates `RethApi`
     pub fn reth_api(&self) -> RethApi<Provider> {
         RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+            .with_slow_subscriber_timeout(self.slow_subscriber_timeout)
     }
 }
 
@@ -1330,9 +1340,16 @@ where
                         .into(),
                         RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
                         RethRpcModule::Reth => {
-                            RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
-                                .into_rpc()
-                                .into()
+                            let reth_api = RethApi::new(
+                                self.provider.clone(),
+                                Box::new(self.executor.clone()),
+                            )
+                            .with_slow_subscriber_timeout(self.slow_subscriber_timeout);
+                            let mut module = RethApiServer::into_rpc(reth_api.clone());
+                            module
+                                .merge(RethPubSubApiServer::into_rpc(reth_api))
+                                .expect("No conflicts");
+                            module.into()
                         }
                         // only relevant for Ethereum and configured in `EthereumAddOns`
                         // implementation
@@ -1384,6 +1401,8 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     jwt_secret: Option<JwtSecret>,
     /// Configurable RPC middleware
     rpc_middleware: RpcServiceBuilder<RpcMiddleware>,
+    /// Requests that take longer than this are logged as slow
+    slow_request_threshold: Option<Duration>,
 }
 
 // === impl RpcServerConfig ===
@@ -1402,6 +1421,7 @@ impl Default for RpcServerConfig<Identity> {
             ipc_endpoint: None,
             jwt_secret: None,
             rpc_middleware: RpcServiceBuilder::new(),
+            slow_request_threshold: None,
         }
     }
 }
@@ -1465,6 +1485,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ipc_endpoint: self.ipc_endpoint,
             jwt_secret: self.jwt_secret,
             rpc_middleware,
+            slow_request_threshold: self.slow_request_threshold,
         }
     }
 
@@ -1537,6 +1558,14 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Configures the threshold above which finished requests are logged as slow.
+    ///
+    /// By default no requests are logged.
+    pub const fn with_slow_request_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.slow_request_threshold = threshold;
+        self
+    }
+
     /// Returns true if any server is configured.
     ///
     /// If no server is configured, no server will be launched on [`RpcServerConfig::start`].
@@ -1602,7 +1631,12 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             constants::DEFAULT_WS_RPC_PORT,
         )));
 
-        let metrics = modules.ipc.as_ref().map(RpcRequestMetrics::ipc).unwrap_or_default();
+        let metrics = modules
+            .ipc
+            .as_ref()
+            .map(RpcRequestMetrics::ipc)
+            .unwrap_or_default()
+            .with_slow_request_threshold(self.slow_request_threshold);
         let ipc_path =
             self.ipc_endpoint.clone().unwrap_or_else(|| constants::DEFAULT_IPC_ENDPOINT.into());
 
@@ -1651,7 +1685,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                                 .as_ref()
                                 .or(modules.ws.as_ref())
                                 .map(RpcRequestMetrics::same_port)
-                                .unwrap_or_default(),
+                                .unwrap_or_default()
+                                .with_slow_request_threshold(self.slow_request_threshold),
                         ),
                     )
                     .build(http_socket_addr)
@@ -1693,9 +1728,14 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                         .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
                 )
                 .set_rpc_middleware(
-                    self.rpc_middleware
-                        .clone()
-                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default()),
+                    self.rpc_middleware.clone().layer(
+                        modules
+                            .ws
+                            .as_ref()
+                            .map(RpcRequestMetrics::ws)
+                            .unwrap_or_default()
+                            .with_slow_request_threshold(self.slow_request_threshold),
+                    ),
                 )
                 .build(ws_socket_addr)
                 .await
@@ -1720,7 +1760,12 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 )
                 .set_rpc_middleware(
                     self.rpc_middleware.clone().layer(
-                        modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
+                        modules
+                            .http
+                            .as_ref()
+                            .map(RpcRequestMetrics::http)
+                            .unwrap_or_default()
+                            .with_slow_request_threshold(self.slow_request_threshold),
                     ),
                 )
                 .build(http_socket_addr)
@@ -2102,6 +2147,16 @@ impl TransportRpcModules {
         http_removed || ws_removed || ipc_removed
     }
 
+    /// Removes all methods for which `f` returns `false` from all configured transports.
+    pub fn retain_methods(&mut self, mut f: impl FnMut(&str) -> bool) {
+        for module in [&mut self.http, &mut self.ws, &mut self.ipc].into_iter().flatten() {
+            let removed = module.method_names().filter(|name| !f(name)).collect::<Vec<_>>();
+            for name in removed {
+                module.remove_method(name);
+            }
+        }
+    }
+
     /// Renames a method in all configured transports by:
     /// 1. Removing the old method name.
     /// 2. Adding the new method.
@@ -2561,6 +2616,30 @@ mod tests {
         assert!(modules.ipc.as_ref().unwrap().method("anything").is_none());
     }
 
+    #[test]
+    fn test_retain_methods() {
+        let module = || {
+            let mut module = create_test_module();
+            module.register_method("denied", |_, _, _| "denied").unwrap();
+            module
+        };
+        let mut modules = TransportRpcModules {
+            http: Some(module()),
+            ws: Some(module()),
+            ipc: Some(module()),
+            ..Default::default()
+        };
+
+        modules.retain_methods(|name| name != "denied");
+
+        // Verify that only the denied method was removed from all transports
+        for module in [&modules.http, &modules.ws, &modules.ipc] {
+            let module = module.as_ref().unwrap();
+            assert!(module.method("denied").is_none());
+            assert!(module.method("anything").is_some());
+        }
+    }
+
     #[test]
     fn test_transport_rpc_module_rename() {
         let mut modules = TransportRpcModules {
@@ -9,9 +9,10 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
-    time::Instant,
+    time::{Duration, Instant},
 };
 use tower::Layer;
+use tracing::warn;
 
 /// Metrics for the RPC server.
 ///
@@ -23,6 +24,8 @@ use tower::Layer;
 #[derive(Default, Debug, Clone)]
 pub(crate) struct RpcRequestMetrics {
     inner: Arc<RpcServerMetricsInner>,
+    /// Requests that take longer than this are logged as slow
+    slow_request_threshold: Option<Duration>,
 }
 
 impl RpcRequestMetrics {
@@ -37,9 +40,22 @@ impl RpcRequestMetrics {
                     })
                     .collect(),
             }),
+            slow_request_threshold: None,
         }
     }
 
+    /// Configures the threshold above which a finished request is logged as slow.
+    pub(crate) const fn with_slow_request_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.slow_request_threshold = threshold;
+        self
+    }
+
+    /// Returns true if a request that took `elapsed` exceeds the configured slow request
+    /// threshold.
+    fn is_slow_request(&self, elapsed: Duration) -> bool {
+        self.slow_request_threshold.is_some_and(|threshold| elapsed > threshold)
+    }
+
     /// Creates a new instance of the metrics layer for HTTP.
     pub(crate) fn http(module: &RpcModule<()>) -> Self {
         Self::new(module, RpcTransport::Http)
@@ -155,7 +171,17 @@ impl<F: Future<Output = MethodResponse>> Future for MeteredRequestFuture<F> {
 
         let res = this.fut.poll(cx);
         if let Poll::Ready(resp) = &res {
-            let elapsed = this.started_at.elapsed().as_secs_f64();
+            let duration = this.started_at.elapsed();
+            let elapsed = duration.as_secs_f64();
+
+            if this.metrics.is_slow_request(duration) {
+                warn!(
+                    target: "rpc::slow",
+                    method = this.method.unwrap_or("unknown"),
+                    elapsed = ?duration,
+                    "Slow RPC request"
+                );
+            }
 
             // update transport metrics
             this.metrics.inner.connection_metrics.requests_finished_total.increment(1);
@@ -231,3 +257,99 @@ struct RpcServerCallMetrics {
     /// Response for a single call
     time_seconds: Histogram,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::types::ResponsePayload;
+    use reth_tracing::tracing_subscriber;
+    use std::{
+        io,
+        sync::{Mutex, PoisonError},
+    };
+
+    /// Service that answers every request after a fixed delay.
+    #[derive(Clone)]
+    struct DelayedService(Duration);
+
+    impl<'a> RpcServiceT<'a> for DelayedService {
+        type Future = Pin<Box<dyn Future<Output = MethodResponse> + Send + 'a>>;
+
+        fn call(&self, req: Request<'a>) -> Self::Future {
+            let delay = self.0;
+            let id = req.id.into_owned();
+            Box::pin(async move {
+                tokio::time::sleep(delay).await;
+                MethodResponse::response(id, ResponsePayload::success("ok"), usize::MAX)
+            })
+        }
+    }
+
+    /// Log writer that keeps everything written to it.
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            String::from_utf8_lossy(&self.0.lock().unwrap_or_else(PoisonError::into_inner))
+                .into_owned()
+        }
+    }
+
+    impl io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap_or_else(PoisonError::into_inner).extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Runs a request for `method` through the metrics middleware and returns the captured logs.
+    async fn logs_for_request(method: &'static str, delay: Duration) -> String {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber =
+            tracing_subscriber::fmt().with_writer(move || writer.clone()).with_ansi(false).finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let mut module = RpcModule::new(());
+        module.register_method(method, |_, _, _| "ok").unwrap();
+        let metrics = RpcRequestMetrics::http(&module)
+            .with_slow_request_threshold(Some(Duration::from_millis(50)));
+        let service = metrics.layer(DelayedService(delay));
+
+        let raw = format!(r#"{{"jsonrpc":"2.0","id":1,"method":"{method}"}}"#);
+        let req: Request<'_> = serde_json::from_str(&raw).unwrap();
+        assert!(service.call(req).await.is_success());
+
+        logs.contents()
+    }
+
+    #[test]
+    fn slow_request_threshold() {
+        let metrics = RpcRequestMetrics::default();
+        assert!(!metrics.is_slow_request(Duration::from_secs(60)));
+
+        let metrics = metrics.with_slow_request_threshold(Some(Duration::from_millis(100)));
+        assert!(!metrics.is_slow_request(Duration::from_millis(10)));
+        assert!(!metrics.is_slow_request(Duration::from_millis(100)));
+        assert!(metrics.is_slow_request(Duration::from_millis(101)));
+    }
+
+    #[tokio::test]
+    async fn warns_on_slow_request() {
+        let logs = logs_for_request("slow_method", Duration::from_millis(200)).await;
+        assert!(logs.contains("rpc::slow"), "{logs}");
+        assert!(logs.contains("Slow RPC request"), "{logs}");
+        assert!(logs.contains("slow_method"), "{logs}");
+    }
+
+    #[tokio::test]
+    async fn quiet_on_fast_request() {
+        let logs = logs_for_request("fast_method", Duration::ZERO).await;
+        assert!(logs.is_empty(), "{logs}");
+    }
+}
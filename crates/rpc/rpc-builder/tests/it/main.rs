@@ -6,5 +6,6 @@ mod middleware;
 mod serde;
 mod startup;
 pub mod utils;
+mod ws;
 
 const fn main() {}
@@ -0,0 +1,73 @@
+//! WS keepalive tests
+
+use std::{net::SocketAddr, time::Duration};
+
+use clap::{Args, Parser};
+use jsonrpsee::{
+    core::client::{Subscription, SubscriptionClientT},
+    rpc_params,
+};
+use reth_node_core::args::RpcServerArgs;
+use reth_rpc::EthApi;
+use reth_rpc_builder::{config::RethRpcServerConfig, RpcServerConfig, TransportRpcModuleConfig};
+use reth_rpc_server_types::RethRpcModule;
+use serde_json::Value;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+use crate::utils::{test_address, test_rpc_builder};
+
+/// A helper type to parse Args more easily
+#[derive(Parser)]
+struct CommandParser<T: Args> {
+    #[command(flatten)]
+    args: T,
+}
+
+/// Returns a WS upgrade request for `addr`.
+fn ws_handshake(addr: SocketAddr) -> String {
+    format!(
+        "GET / HTTP/1.1\r\nHost: {addr}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+         Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n"
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_ws_idle_timeout_reaps_unresponsive_clients() {
+    // the idle timeout is derived from the interval
+    let args = CommandParser::<RpcServerArgs>::parse_from(["reth", "--ws.ping-interval", "1"]).args;
+    let modules = test_rpc_builder().build(
+        TransportRpcModuleConfig::set_ws(vec![RethRpcModule::Eth]),
+        Box::new(EthApi::with_spawner),
+    );
+    let handle = RpcServerConfig::ws(args.http_ws_server_builder().unwrap())
+        .with_ws_address(test_address())
+        .start(&modules)
+        .await
+        .unwrap();
+    let addr = handle.ws_local_addr().unwrap();
+
+    // answers pings, but otherwise only holds a subscription that never fires
+    let client = handle.ws_client().await.unwrap();
+    let _sub: Subscription<Value> = client
+        .subscribe("eth_subscribe", rpc_params!["newHeads"], "eth_unsubscribe")
+        .await
+        .unwrap();
+
+    // completes the upgrade but never answers a ping
+    let mut unresponsive = TcpStream::connect(addr).await.unwrap();
+    unresponsive.write_all(ws_handshake(addr).as_bytes()).await.unwrap();
+    let reaped = tokio::time::timeout(Duration::from_secs(10), async {
+        let mut buf = [0u8; 1024];
+        while matches!(unresponsive.read(&mut buf).await, Ok(read) if read > 0) {}
+    })
+    .await;
+
+    assert!(reaped.is_ok(), "unresponsive connection was not closed");
+
+    // stays connected across several more timeouts
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    assert!(client.is_connected());
+}
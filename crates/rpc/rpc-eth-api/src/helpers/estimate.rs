@@ -12,7 +12,7 @@ use reth_provider::StateProvider;
 use reth_revm::{database::StateProviderDatabase, db::CacheDB};
 use reth_rpc_eth_types::{
     error::api::FromEvmHalt,
-    revm_utils::{apply_state_overrides, caller_gas_allowance},
+    revm_utils::{apply_estimate_gas_buffer, apply_state_overrides, caller_gas_allowance},
     EthApiError, RevertError, RpcInvalidTransactionError,
 };
 use reth_rpc_server_types::constants::gas_oracle::{CALL_STIPEND_GAS, ESTIMATE_GAS_ERROR_RATIO};
@@ -22,9 +22,25 @@ use tracing::trace;
 
 /// Gas execution estimates
 pub trait EstimateCall: Call {
+    /// Returns the percentage added on top of the binary search result of `eth_estimateGas`.
+    ///
+    /// Plain transfers are not buffered.
+    ///
+    /// Data access in default trait method implementations.
+    fn estimate_gas_buffer_percent(&self) -> u64;
+
+    /// Returns the maximum gas limit tried by `eth_estimateGas`, if any.
+    ///
+    /// Data access in default trait method implementations.
+    fn estimate_gas_cap(&self) -> Option<u64>;
+
     /// Estimates the gas usage of the `request` with the state.
     ///
-    /// This will execute the [`TransactionRequest`] and find the best gas limit via binary search.
+    /// This will execute the [`TransactionRequest`] and find the best gas limit via binary search,
+    /// bounded by the block gas limit and [`EstimateCall::estimate_gas_cap`]. The result is then
+    /// increased by [`EstimateCall::estimate_gas_buffer_percent`], within the same bounds and the
+    /// caller's gas allowance. Plain transfers to accounts without code return
+    /// [`MIN_TRANSACTION_GAS`] as is, since their gas usage cannot change.
     ///
     /// ## EVM settings
     ///
@@ -72,6 +88,9 @@ pub trait EstimateCall: Call {
                 tx_gas_limit
             })
             .unwrap_or(block_env_gas_limit);
+        if let Some(estimate_gas_cap) = self.estimate_gas_cap() {
+            highest_gas_limit = highest_gas_limit.min(estimate_gas_cap);
+        }
 
         // Configure the evm env
         let mut db = CacheDB::new(StateProviderDatabase::new(state));
@@ -176,6 +195,8 @@ pub trait EstimateCall: Call {
         // we know the tx succeeded with the configured gas limit, so we can use that as the
         // highest, in case we applied a gas cap due to caller allowance above
         highest_gas_limit = tx_env.gas_limit();
+        // the buffered estimate must not exceed what the transaction can afford
+        let max_gas_limit = highest_gas_limit;
 
         // NOTE: this is the gas the transaction used, which is less than the
         // transaction requires to succeed.
@@ -257,7 +278,11 @@ pub trait EstimateCall: Call {
             mid_gas_limit = ((highest_gas_limit as u128 + lowest_gas_limit as u128) / 2) as u64;
         }
 
-        Ok(U256::from(highest_gas_limit))
+        Ok(U256::from(apply_estimate_gas_buffer(
+            highest_gas_limit,
+            self.estimate_gas_buffer_percent(),
+            max_gas_limit,
+        )))
     }
 
     /// Estimate gas needed for execution of the `request` at the [`BlockId`].
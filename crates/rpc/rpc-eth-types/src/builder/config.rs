@@ -35,6 +35,17 @@ pub struct EthConfig {
     pub rpc_gas_cap: u64,
     /// Max number of blocks for `eth_simulateV1`.
     pub rpc_max_simulate_blocks: u64,
+    /// Percentage added on top of the `eth_estimateGas` result, capped at the block gas limit.
+    ///
+    /// Defaults to `0`, which returns the raw estimate.
+    pub estimate_gas_buffer_percent: u64,
+    /// Maximum gas limit tried by `eth_estimateGas`, in addition to the block gas limit.
+    pub estimate_gas_cap: Option<u64>,
+    /// How long a subscription notification may wait for room in the connection's message
+    /// buffer before the subscription is closed.
+    ///
+    /// Defaults to `None`, which waits for as long as the subscriber stays connected.
+    pub slow_subscriber_timeout: Option<Duration>,
     ///
     /// Sets TTL for stale filters
     pub stale_filter_ttl: Duration,
@@ -65,6 +76,9 @@ impl Default for EthConfig {
             max_logs_per_response: DEFAULT_MAX_LOGS_PER_RESPONSE,
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
             rpc_max_simulate_blocks: DEFAULT_MAX_SIMULATE_BLOCKS,
+            estimate_gas_buffer_percent: 0,
+            estimate_gas_cap: None,
+            slow_subscriber_timeout: None,
             stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
             fee_history_cache: FeeHistoryCacheConfig::default(),
             proof_permits: DEFAULT_PROOF_PERMITS,
@@ -115,6 +129,24 @@ impl EthConfig {
         self
     }
 
+    /// Configures the percentage added on top of `eth_estimateGas` results
+    pub const fn estimate_gas_buffer_percent(mut self, percent: u64) -> Self {
+        self.estimate_gas_buffer_percent = percent;
+        self
+    }
+
+    /// Configures the maximum gas limit tried by `eth_estimateGas`
+    pub const fn estimate_gas_cap(mut self, cap: Option<u64>) -> Self {
+        self.estimate_gas_cap = cap;
+        self
+    }
+
+    /// Configures how long a subscription may stall on a full message buffer before it is closed
+    pub const fn slow_subscriber_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.slow_subscriber_timeout = timeout;
+        self
+    }
+
     /// Configures the maximum proof window for historical proof generation.
     pub const fn eth_proof_window(mut self, window: u64) -> Self {
         self.eth_proof_window = window;
@@ -44,7 +44,7 @@ pub struct GasPriceOracleConfig {
     /// The maximum number of blocks for estimating gas price
     pub max_block_history: u64,
 
-    /// The default gas price to use if there are no blocks to use
+    /// The gas price to use if there are fewer populated blocks than `blocks`
     pub default: Option<U256>,
 
     /// The maximum gas price to use for the estimate
@@ -193,6 +193,13 @@ where
             )
         };
 
+        // fall back to the configured default if there is not enough history for an estimate
+        if let Some(default) = self.oracle_config.default {
+            if populated_blocks < self.oracle_config.blocks {
+                price = default;
+            }
+        }
+
         // constrain to the max price
         if let Some(max_price) = self.oracle_config.max_price {
             if price > max_price {
@@ -319,6 +326,56 @@ impl Default for GasCap {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_consensus::Header;
+    use reth_provider::test_utils::MockEthProvider;
+
+    /// Returns an oracle over a chain of `len` blocks whose tips, `tips(number)`, are already
+    /// cached.
+    async fn oracle_with_tips(
+        len: u64,
+        tips: impl Fn(u64) -> Vec<U256>,
+        config: GasPriceOracleConfig,
+    ) -> GasPriceOracle<MockEthProvider> {
+        let provider = MockEthProvider::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let oracle = GasPriceOracle::new(provider.clone(), config, cache);
+
+        let mut inner = oracle.inner.lock().await;
+        let mut parent_hash = B256::ZERO;
+        for number in 0..len {
+            let header = Header { number, parent_hash, ..Default::default() };
+            let hash = header.hash_slow();
+            provider.add_header(hash, header);
+            inner.lowest_effective_tip_cache.insert(hash, (parent_hash, tips(number)));
+            parent_hash = hash;
+        }
+        drop(inner);
+
+        oracle
+    }
+
+    #[tokio::test]
+    async fn default_price_without_populated_blocks() {
+        let default = U256::from(7);
+        let config = GasPriceOracleConfig { default: Some(default), ..Default::default() };
+        let oracle = oracle_with_tips(5, |_| Vec::new(), config).await;
+
+        assert_eq!(oracle.suggest_tip_cap().await.unwrap(), default);
+
+        // an estimate for an older head does not replace the default
+        oracle.inner.lock().await.last_price =
+            GasPriceOracleResult { block_hash: B256::with_last_byte(1), price: U256::from(100) };
+        assert_eq!(oracle.suggest_tip_cap().await.unwrap(), default);
+    }
+
+    #[tokio::test]
+    async fn default_price_with_populated_blocks() {
+        let config =
+            GasPriceOracleConfig { blocks: 1, default: Some(U256::from(7)), ..Default::default() };
+        let oracle = oracle_with_tips(5, |_| vec![U256::from(50)], config).await;
+
+        assert_eq!(oracle.suggest_tip_cap().await.unwrap(), U256::from(50));
+    }
 
     #[test]
     fn max_price_sanity() {
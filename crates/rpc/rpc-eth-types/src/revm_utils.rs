@@ -46,6 +46,14 @@ where
         .saturating_to())
 }
 
+/// Adds `buffer_percent` percent on top of a gas `estimate`, never exceeding `gas_limit_cap`.
+///
+/// A `buffer_percent` of `0` returns the raw estimate.
+pub fn apply_estimate_gas_buffer(estimate: u64, buffer_percent: u64, gas_limit_cap: u64) -> u64 {
+    let buffer = u64::try_from(estimate as u128 * buffer_percent as u128 / 100).unwrap_or(u64::MAX);
+    estimate.saturating_add(buffer).min(gas_limit_cap.max(estimate))
+}
+
 /// Helper type for representing the fees of a `TransactionRequest`
 #[derive(Debug)]
 pub struct CallFees {
@@ -310,6 +318,18 @@ mod tests {
     use super::*;
     use alloy_consensus::constants::GWEI_TO_WEI;
 
+    #[test]
+    fn test_estimate_gas_buffer() {
+        let raw = 100_000;
+        assert_eq!(apply_estimate_gas_buffer(raw, 0, 30_000_000), raw);
+        assert_eq!(apply_estimate_gas_buffer(raw, 20, 30_000_000), raw * 120 / 100);
+        // clamped to the cap, but never below the raw estimate
+        assert_eq!(apply_estimate_gas_buffer(raw, 20, 110_000), 110_000);
+        assert_eq!(apply_estimate_gas_buffer(raw, 20, 90_000), raw);
+        // huge buffers saturate instead of wrapping around
+        assert_eq!(apply_estimate_gas_buffer(u64::MAX / 2, u64::MAX, u64::MAX), u64::MAX);
+    }
+
     #[test]
     fn test_ensure_0_fallback() {
         let CallFees { gas_price, .. } =
@@ -27,6 +27,8 @@ where
     evm_config: EvmConfig,
     gas_cap: GasCap,
     max_simulate_blocks: u64,
+    estimate_gas_buffer_percent: u64,
+    estimate_gas_cap: Option<u64>,
     eth_proof_window: u64,
     fee_history_cache: FeeHistoryCache,
     proof_permits: usize,
@@ -54,6 +56,8 @@ where
             gas_oracle: None,
             gas_cap: GasCap::default(),
             max_simulate_blocks: DEFAULT_MAX_SIMULATE_BLOCKS,
+            estimate_gas_buffer_percent: 0,
+            estimate_gas_cap: None,
             eth_proof_window: DEFAULT_ETH_PROOF_WINDOW,
             blocking_task_pool: None,
             fee_history_cache: FeeHistoryCache::new(Default::default()),
@@ -95,6 +99,18 @@ where
         self
     }
 
+    /// Sets the percentage added on top of `eth_estimateGas` results.
+    pub const fn estimate_gas_buffer_percent(mut self, estimate_gas_buffer_percent: u64) -> Self {
+        self.estimate_gas_buffer_percent = estimate_gas_buffer_percent;
+        self
+    }
+
+    /// Sets the maximum gas limit tried by `eth_estimateGas`.
+    pub const fn estimate_gas_cap(mut self, estimate_gas_cap: Option<u64>) -> Self {
+        self.estimate_gas_cap = estimate_gas_cap;
+        self
+    }
+
     /// Sets the maximum number of blocks into the past for generating state proofs.
     pub const fn eth_proof_window(mut self, eth_proof_window: u64) -> Self {
         self.eth_proof_window = eth_proof_window;
@@ -140,6 +156,8 @@ where
             gas_oracle,
             gas_cap,
             max_simulate_blocks,
+            estimate_gas_buffer_percent,
+            estimate_gas_cap,
             eth_proof_window,
             blocking_task_pool,
             fee_history_cache,
@@ -161,6 +179,8 @@ where
             gas_oracle,
             gas_cap,
             max_simulate_blocks,
+            estimate_gas_buffer_percent,
+            estimate_gas_cap,
             eth_proof_window,
             blocking_task_pool.unwrap_or_else(|| {
                 BlockingTaskPool::build().expect("failed to build blocking task pool")
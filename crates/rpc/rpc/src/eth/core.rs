@@ -120,6 +120,8 @@ where
         gas_oracle: GasPriceOracle<Provider>,
         gas_cap: impl Into<GasCap>,
         max_simulate_blocks: u64,
+        estimate_gas_buffer_percent: u64,
+        estimate_gas_cap: Option<u64>,
         eth_proof_window: u64,
         blocking_task_pool: BlockingTaskPool,
         fee_history_cache: FeeHistoryCache,
@@ -134,6 +136,8 @@ where
             gas_oracle,
             gas_cap,
             max_simulate_blocks,
+            estimate_gas_buffer_percent,
+            estimate_gas_cap,
             eth_proof_window,
             blocking_task_pool,
             fee_history_cache,
@@ -176,6 +180,8 @@ where
             ctx.new_gas_price_oracle(),
             ctx.config.rpc_gas_cap,
             ctx.config.rpc_max_simulate_blocks,
+            ctx.config.estimate_gas_buffer_percent,
+            ctx.config.estimate_gas_cap,
             ctx.config.eth_proof_window,
             blocking_task_pool,
             ctx.new_fee_history_cache(),
@@ -301,6 +307,10 @@ pub struct EthApiInner<Provider: BlockReader, Pool, Network, EvmConfig> {
     gas_cap: u64,
     /// Maximum number of blocks for `eth_simulateV1`.
     max_simulate_blocks: u64,
+    /// Percentage added on top of `eth_estimateGas` results.
+    estimate_gas_buffer_percent: u64,
+    /// Maximum gas limit tried by `eth_estimateGas`.
+    estimate_gas_cap: Option<u64>,
     /// The maximum number of blocks into the past for generating state proofs.
     eth_proof_window: u64,
     /// The block number at which the node started
@@ -337,6 +347,8 @@ where
         gas_oracle: GasPriceOracle<Provider>,
         gas_cap: impl Into<GasCap>,
         max_simulate_blocks: u64,
+        estimate_gas_buffer_percent: u64,
+        estimate_gas_cap: Option<u64>,
         eth_proof_window: u64,
         blocking_task_pool: BlockingTaskPool,
         fee_history_cache: FeeHistoryCache,
@@ -366,6 +378,8 @@ where
             gas_oracle,
             gas_cap: gas_cap.into().into(),
             max_simulate_blocks,
+            estimate_gas_buffer_percent,
+            estimate_gas_cap,
             eth_proof_window,
             starting_block,
             task_spawner,
@@ -439,6 +453,18 @@ where
         self.max_simulate_blocks
     }
 
+    /// Returns the `estimate_gas_buffer_percent`.
+    #[inline]
+    pub const fn estimate_gas_buffer_percent(&self) -> u64 {
+        self.estimate_gas_buffer_percent
+    }
+
+    /// Returns the `estimate_gas_cap`.
+    #[inline]
+    pub const fn estimate_gas_cap(&self) -> Option<u64> {
+        self.estimate_gas_cap
+    }
+
     /// Returns a handle to the gas oracle.
     #[inline]
     pub const fn gas_oracle(&self) -> &GasPriceOracle<Provider> {
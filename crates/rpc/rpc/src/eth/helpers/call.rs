@@ -134,4 +134,104 @@ where
     Self: Call,
     Provider: BlockReader,
 {
+    #[inline]
+    fn estimate_gas_buffer_percent(&self) -> u64 {
+        self.inner.estimate_gas_buffer_percent()
+    }
+
+    #[inline]
+    fn estimate_gas_cap(&self) -> Option<u64> {
+        self.inner.estimate_gas_cap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M;
+    use alloy_primitives::{bytes, Address, Bytes};
+    use reth_chainspec::MIN_TRANSACTION_GAS;
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_provider::{
+        test_utils::{ExtendedAccount, MockEthProvider},
+        ChainSpecProvider, StateProviderFactory,
+    };
+    use reth_rpc_eth_types::{
+        revm_utils::apply_estimate_gas_buffer, EthStateCache, FeeHistoryCache,
+        FeeHistoryCacheConfig, GasPriceOracle,
+    };
+    use reth_rpc_server_types::constants::{
+        DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_PROOF_PERMITS,
+    };
+    use reth_tasks::pool::BlockingTaskPool;
+    use reth_transaction_pool::test_utils::testing_pool;
+
+    /// Stores `1` in slot `0`.
+    const STORE_CODE: Bytes = bytes!("600160005500");
+
+    /// Estimates a call to `to`, with a contract running [`STORE_CODE`] deployed at `0xaa`.
+    fn estimate_call(
+        to: Address,
+        buffer_percent: u64,
+        cap: Option<u64>,
+    ) -> Result<u64, EthApiError> {
+        let provider = MockEthProvider::default();
+        provider.add_account(
+            Address::with_last_byte(0xaa),
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(STORE_CODE),
+        );
+
+        let evm_config = EthEvmConfig::new(provider.chain_spec());
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache),
+            ETHEREUM_BLOCK_GAS_LIMIT_30M,
+            DEFAULT_MAX_SIMULATE_BLOCKS,
+            buffer_percent,
+            cap,
+            DEFAULT_ETH_PROOF_WINDOW,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            FeeHistoryCache::new(FeeHistoryCacheConfig::default()),
+            evm_config,
+            DEFAULT_PROOF_PERMITS,
+        );
+
+        let mut evm_env = EvmEnv::default();
+        evm_env.block_env.gas_limit = ETHEREUM_BLOCK_GAS_LIMIT_30M;
+        let request = TransactionRequest::default().to(to);
+        let state = provider.latest().unwrap();
+        eth_api.estimate_gas_with(evm_env, request, state, None).map(|gas| gas.to())
+    }
+
+    fn estimate(buffer_percent: u64, cap: Option<u64>) -> Result<u64, EthApiError> {
+        estimate_call(Address::with_last_byte(0xaa), buffer_percent, cap)
+    }
+
+    #[tokio::test]
+    async fn test_estimate_gas_buffer() {
+        let raw = estimate(0, None).unwrap();
+        assert!(raw > MIN_TRANSACTION_GAS);
+
+        let buffered = estimate(20, None).unwrap();
+        assert_eq!(buffered, raw * 120 / 100);
+        assert_eq!(buffered, apply_estimate_gas_buffer(raw, 20, ETHEREUM_BLOCK_GAS_LIMIT_30M));
+
+        // plain transfers need exactly the intrinsic gas and are not buffered
+        let transfer = estimate_call(Address::with_last_byte(0xbb), 20, None).unwrap();
+        assert_eq!(transfer, MIN_TRANSACTION_GAS);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_gas_cap() {
+        let raw = estimate(0, None).unwrap();
+
+        // the buffer is clamped to the cap
+        assert_eq!(estimate(20, Some(raw + 1)).unwrap(), raw + 1);
+        // the transaction cannot succeed within the cap
+        assert!(estimate(0, Some(MIN_TRANSACTION_GAS)).is_err());
+    }
 }
@@ -1,6 +1,6 @@
 //! `eth_` `PubSub` RPC handler implementation
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use alloy_primitives::TxHash;
 use alloy_rpc_types_eth::{
@@ -22,11 +22,12 @@ use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::{NewTransactionEvent, PoolConsensusTx, TransactionPool};
 use serde::Serialize;
+use tokio::sync::oneshot;
 use tokio_stream::{
     wrappers::{BroadcastStream, ReceiverStream},
     Stream,
 };
-use tracing::error;
+use tracing::{error, warn};
 
 /// `Eth` pubsub RPC implementation.
 ///
@@ -37,6 +38,8 @@ pub struct EthPubSub<Eth> {
     inner: Arc<EthPubSubInner<Eth>>,
     /// The type that's used to spawn subscription tasks.
     subscription_task_spawner: Box<dyn TaskSpawner>,
+    /// How long a notification may wait for room in the connection's message buffer.
+    slow_subscriber_timeout: Option<Duration>,
 }
 
 // === impl EthPubSub ===
@@ -52,7 +55,16 @@ impl<Eth> EthPubSub<Eth> {
     /// Creates a new, shareable instance.
     pub fn with_spawner(eth_api: Eth, subscription_task_spawner: Box<dyn TaskSpawner>) -> Self {
         let inner = EthPubSubInner { eth_api };
-        Self { inner: Arc::new(inner), subscription_task_spawner }
+        Self { inner: Arc::new(inner), subscription_task_spawner, slow_subscriber_timeout: None }
+    }
+
+    /// Closes subscriptions whose next notification finds no room in the connection's message
+    /// buffer within `timeout`.
+    ///
+    /// By default notifications wait for as long as the subscriber stays connected.
+    pub const fn with_slow_subscriber_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.slow_subscriber_timeout = timeout;
+        self
     }
 }
 
@@ -75,11 +87,18 @@ where
     ) -> jsonrpsee::core::SubscriptionResult {
         let sink = pending.accept().await?;
         let pubsub = self.inner.clone();
+        let timeout = self.slow_subscriber_timeout;
+        let (tx, rx) = oneshot::channel();
         self.subscription_task_spawner.spawn(Box::pin(async move {
-            let _ = handle_accepted(pubsub, sink, kind, params).await;
+            let _ = tx.send(handle_accepted(pubsub, sink, kind, params, timeout).await);
         }));
 
-        Ok(())
+        // an error returned after the subscription was accepted is sent to the client as the
+        // subscription's close notification
+        match rx.await {
+            Ok(Err(err)) => Err(err.message().into()),
+            _ => Ok(()),
+        }
     }
 }
 
@@ -89,6 +108,7 @@ async fn handle_accepted<Eth>(
     accepted_sink: SubscriptionSink,
     kind: SubscriptionKind,
     params: Option<Params>,
+    slow_subscriber_timeout: Option<Duration>,
 ) -> Result<(), ErrorObject<'static>>
 where
     Eth: RpcNodeCore<
@@ -99,7 +119,8 @@ where
 {
     match kind {
         SubscriptionKind::NewHeads => {
-            pipe_from_stream(accepted_sink, pubsub.new_headers_stream()).await
+            pipe_from_stream(accepted_sink, pubsub.new_headers_stream(), slow_subscriber_timeout)
+                .await
         }
         SubscriptionKind::Logs => {
             // if no params are provided, used default filter params
@@ -110,7 +131,8 @@ where
                 }
                 _ => FilteredParams::default(),
             };
-            pipe_from_stream(accepted_sink, pubsub.log_stream(filter)).await
+            pipe_from_stream(accepted_sink, pubsub.log_stream(filter), slow_subscriber_timeout)
+                .await
         }
         SubscriptionKind::NewPendingTransactions => {
             if let Some(params) = params {
@@ -134,7 +156,8 @@ where
                             };
                             std::future::ready(tx_value)
                         });
-                        return pipe_from_stream(accepted_sink, stream).await
+                        return pipe_from_stream(accepted_sink, stream, slow_subscriber_timeout)
+                            .await
                     }
                     Params::Bool(false) | Params::None => {
                         // only hashes requested
@@ -147,7 +170,12 @@ where
                 }
             }
 
-            pipe_from_stream(accepted_sink, pubsub.pending_transaction_hashes_stream()).await
+            pipe_from_stream(
+                accepted_sink,
+                pubsub.pending_transaction_hashes_stream(),
+                slow_subscriber_timeout,
+            )
+            .await
         }
         SubscriptionKind::Syncing => {
             // get new block subscription
@@ -204,9 +232,13 @@ impl From<SubscriptionSerializeError> for ErrorObject<'static> {
 }
 
 /// Pipes all stream items to the subscription sink.
-async fn pipe_from_stream<T, St>(
+///
+/// If `slow_subscriber_timeout` is set, the subscription is ended with an error once an item is
+/// not accepted within it.
+pub(crate) async fn pipe_from_stream<T, St>(
     sink: SubscriptionSink,
     mut stream: St,
+    slow_subscriber_timeout: Option<Duration>,
 ) -> Result<(), ErrorObject<'static>>
 where
     St: Stream<Item = T> + Unpin,
@@ -227,8 +259,29 @@ where
                     },
                 };
                 let msg = SubscriptionMessage::from_json(&item).map_err(SubscriptionSerializeError::new)?;
-                if sink.send(msg).await.is_err() {
-                    break Ok(());
+                let sent = match slow_subscriber_timeout {
+                    Some(timeout) => {
+                        tokio::time::timeout(timeout, sink.send(msg)).await.map_err(|_| timeout)
+                    }
+                    None => Ok(sink.send(msg).await),
+                };
+                match sent {
+                    Ok(Ok(())) => {}
+                    // connection dropped
+                    Ok(Err(_)) => break Ok(()),
+                    Err(timeout) => {
+                        // the connection's message buffer stayed full, drop the subscription
+                        // instead of holding back all further notifications
+                        warn!(
+                            target: "rpc::eth",
+                            subscription = ?sink.subscription_id(),
+                            ?timeout,
+                            "Dropping subscription of slow subscriber"
+                        );
+                        break Err(internal_rpc_err(format!(
+                            "subscription closed: notification not accepted within {timeout:?}"
+                        )))
+                    }
                 }
             }
         }
@@ -324,3 +377,61 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{rpc_params, RpcModule};
+    use tokio::sync::mpsc;
+
+    type PipeResult = Result<(), ErrorObject<'static>>;
+
+    /// Returns a module whose subscription pipes ten items and reports how piping ended.
+    fn items_module(
+        timeout: Option<Duration>,
+    ) -> (RpcModule<mpsc::UnboundedSender<PipeResult>>, mpsc::UnboundedReceiver<PipeResult>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut module = RpcModule::new(tx);
+        module
+            .register_subscription(
+                "subscribe_items",
+                "s_items",
+                "unsubscribe_items",
+                move |_, pending, tx, _| async move {
+                    let sink = pending.accept().await?;
+                    let stream = futures::stream::iter(0..10usize);
+                    let _ = tx.send(pipe_from_stream(sink, stream, timeout).await);
+                    Ok(())
+                },
+            )
+            .unwrap();
+        (module, rx)
+    }
+
+    #[tokio::test]
+    async fn responsive_subscriber_receives_all_items() {
+        let (module, mut rx) = items_module(Some(Duration::from_millis(10)));
+        let _sub = module.subscribe("subscribe_items", rpc_params![], 16).await.unwrap();
+
+        assert!(rx.recv().await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn drops_slow_subscriber() {
+        let (module, mut rx) = items_module(Some(Duration::from_millis(10)));
+        // room for a single notification that is never read
+        let _sub = module.subscribe("subscribe_items", rpc_params![], 1).await.unwrap();
+
+        let err = rx.recv().await.unwrap().unwrap_err();
+        assert!(err.message().starts_with("subscription closed"));
+    }
+
+    #[tokio::test]
+    async fn keeps_slow_subscriber_without_timeout() {
+        let (module, mut rx) = items_module(None);
+        // room for a single notification that is never read
+        let _sub = module.subscribe("subscribe_items", rpc_params![], 1).await.unwrap();
+
+        assert!(tokio::time::timeout(Duration::from_millis(100), rx.recv()).await.is_err());
+    }
+}
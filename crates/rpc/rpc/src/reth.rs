@@ -1,21 +1,29 @@
-use std::{collections::HashMap, future::Future, sync::Arc};
+use std::{collections::HashMap, future::Future, sync::Arc, time::Duration};
 
 use alloy_eips::BlockId;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, BlockNumber, B256, U256};
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult;
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult, PendingSubscriptionSink};
 use reth_errors::RethResult;
-use reth_provider::{BlockReaderIdExt, ChangeSetReader, StateProviderFactory};
-use reth_rpc_api::RethApiServer;
+use reth_provider::{
+    BlockReaderIdExt, CanonStateSubscriptions, ChangeSetReader, ExecutionOutcome,
+    StateProviderFactory,
+};
+use reth_rpc_api::{RethApiServer, RethPubSubApiServer};
 use reth_rpc_eth_types::{EthApiError, EthResult};
 use reth_tasks::TaskSpawner;
 use tokio::sync::oneshot;
 
+use crate::eth::pubsub::pipe_from_stream;
+
 /// `reth` API implementation.
 ///
 /// This type provides the functionality for handling `reth` prototype RPC requests.
 pub struct RethApi<Provider> {
     inner: Arc<RethApiInner<Provider>>,
+    /// How long a notification may wait for room in the connection's message buffer.
+    slow_subscriber_timeout: Option<Duration>,
 }
 
 // === impl RethApi ===
@@ -29,7 +37,16 @@ impl<Provider> RethApi<Provider> {
     /// Create a new instance of the [`RethApi`]
     pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
         let inner = Arc::new(RethApiInner { provider, task_spawner });
-        Self { inner }
+        Self { inner, slow_subscriber_timeout: None }
+    }
+
+    /// Closes subscriptions whose next notification finds no room in the connection's message
+    /// buffer within `timeout`.
+    ///
+    /// By default notifications wait for as long as the subscriber stays connected.
+    pub const fn with_slow_subscriber_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.slow_subscriber_timeout = timeout;
+        self
     }
 }
 
@@ -99,6 +116,127 @@ where
     }
 }
 
+#[async_trait]
+impl<Provider> RethPubSubApiServer for RethApi<Provider>
+where
+    Provider: CanonStateSubscriptions + 'static,
+{
+    /// Handler for `reth_subscribeStorageChanges`
+    async fn reth_subscribe_storage_changes(
+        &self,
+        pending: PendingSubscriptionSink,
+        address: Address,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        subscribe_storage_changes(
+            pending,
+            self.provider(),
+            address,
+            &*self.inner.task_spawner,
+            self.slow_subscriber_timeout,
+        )
+        .await
+    }
+}
+
+/// Pipes the storage changes of `address` in each newly committed block to the subscription.
+async fn subscribe_storage_changes(
+    pending: PendingSubscriptionSink,
+    subscriptions: &impl CanonStateSubscriptions,
+    address: Address,
+    task_spawner: &dyn TaskSpawner,
+    slow_subscriber_timeout: Option<Duration>,
+) -> jsonrpsee::core::SubscriptionResult {
+    // subscribe before accepting, so no block committed after the client is told about the
+    // subscription is missed
+    let stream = subscriptions.canonical_state_stream().flat_map(move |notification| {
+        // slots changed by reverted blocks are announced again with their restored value, before
+        // the new blocks that may change them once more
+        let restored = notification
+            .reverted()
+            .map(|reverted| restored_storage(reverted.execution_outcome(), address))
+            .filter(|restored| !restored.is_empty());
+        let changes =
+            storage_changes_by_block(notification.committed().execution_outcome(), address)
+                .into_iter()
+                .map(|(_, changes)| changes);
+        futures::stream::iter(restored.into_iter().chain(changes))
+    });
+    let sink = pending.accept().await?;
+    let (tx, rx) = oneshot::channel();
+    task_spawner.spawn(Box::pin(async move {
+        let _ = tx.send(pipe_from_stream(sink, stream, slow_subscriber_timeout).await);
+    }));
+
+    // sent to the client as the subscription's close notification
+    match rx.await {
+        Ok(Err(err)) => Err(err.message().into()),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the storage slots of `address` changed by each block of the execution outcome, mapped
+/// to their value at the end of that block.
+///
+/// Blocks that don't change the account's storage are skipped.
+fn storage_changes_by_block<R>(
+    outcome: &ExecutionOutcome<R>,
+    address: Address,
+) -> Vec<(BlockNumber, HashMap<B256, B256>)> {
+    let bundle = &outcome.bundle;
+    let Some(account) = bundle.account(&address) else { return Vec::new() };
+
+    // the reverts of each block record which slots the block changed and their previous value
+    let changed_slots = bundle
+        .reverts
+        .iter()
+        .map(|reverts| {
+            reverts.iter().find(|(revert_address, _)| *revert_address == address).map(
+                |(_, revert)| &revert.storage,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    changed_slots
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, slots)| {
+            let slots = slots.filter(|slots| !slots.is_empty())?;
+            let changes = slots
+                .keys()
+                .map(|slot| {
+                    // the value at the end of this block is the previous value recorded by the
+                    // next block that changes the slot, or the present value if there is none
+                    let value = changed_slots[idx + 1..]
+                        .iter()
+                        .flatten()
+                        .find_map(|later| later.get(slot))
+                        .map(|revert| revert.to_previous_value())
+                        .or_else(|| account.storage_slot(*slot))
+                        .unwrap_or_default();
+                    (B256::from(*slot), B256::from(value))
+                })
+                .collect();
+            Some((outcome.first_block() + idx as u64, changes))
+        })
+        .collect()
+}
+
+/// Returns the storage slots of `address` changed by the blocks of the execution outcome, mapped
+/// to their value before the first of those blocks.
+fn restored_storage<R>(outcome: &ExecutionOutcome<R>, address: Address) -> HashMap<B256, B256> {
+    let mut restored = HashMap::new();
+    // the earliest revert of a slot holds its value before the outcome's blocks
+    let reverts = outcome.bundle.reverts.iter().flatten();
+    for (_, revert) in reverts.filter(|(revert_address, _)| *revert_address == address) {
+        for (slot, value) in &revert.storage {
+            restored
+                .entry(B256::from(*slot))
+                .or_insert_with(|| B256::from(value.to_previous_value()));
+        }
+    }
+    restored
+}
+
 impl<Provider> std::fmt::Debug for RethApi<Provider> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RethApi").finish_non_exhaustive()
@@ -107,7 +245,10 @@ impl<Provider> std::fmt::Debug for RethApi<Provider> {
 
 impl<Provider> Clone for RethApi<Provider> {
     fn clone(&self) -> Self {
-        Self { inner: Arc::clone(&self.inner) }
+        Self {
+            inner: Arc::clone(&self.inner),
+            slow_subscriber_timeout: self.slow_subscriber_timeout,
+        }
     }
 }
 
@@ -117,3 +258,178 @@ struct RethApiInner<Provider> {
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{rpc_params, RpcModule};
+    use reth_provider::{test_utils::TestCanonStateSubscriptions, Chain};
+    use reth_revm::db::BundleState;
+    use reth_tasks::TokioTaskExecutor;
+    use revm::state::AccountInfo;
+
+    /// Returns a single block chain in which `address` changes `slot` from `previous` to
+    /// `value`.
+    fn slot_change(address: Address, slot: U256, previous: U256, value: U256) -> Arc<Chain> {
+        let bundle = BundleState::new(
+            [(
+                address,
+                None,
+                Some(AccountInfo::default()),
+                [(slot, (previous, value))].into_iter().collect(),
+            )],
+            vec![vec![(address, Some(None), vec![(slot, previous)])]],
+            vec![],
+        );
+        let outcome = ExecutionOutcome { bundle, ..Default::default() };
+        Arc::new(Chain::from_block(Default::default(), outcome, None))
+    }
+
+    /// Returns a module serving the storage changes subscription of `address`.
+    fn storage_changes_module(
+        subscriptions: TestCanonStateSubscriptions,
+        address: Address,
+        slow_subscriber_timeout: Option<Duration>,
+    ) -> RpcModule<TestCanonStateSubscriptions> {
+        let mut module = RpcModule::new(subscriptions);
+        module
+            .register_subscription(
+                "subscribe_storage",
+                "s_storage",
+                "unsubscribe_storage",
+                move |_, pending, subscriptions, _| async move {
+                    let executor = TokioTaskExecutor::default();
+                    subscribe_storage_changes(
+                        pending,
+                        &*subscriptions,
+                        address,
+                        &executor,
+                        slow_subscriber_timeout,
+                    )
+                    .await
+                },
+            )
+            .unwrap();
+        module
+    }
+
+    #[test]
+    fn storage_changes_per_block() {
+        let address = Address::with_last_byte(1);
+        let other = Address::with_last_byte(2);
+        let slot_a = U256::from(1);
+        let slot_b = U256::from(2);
+
+        // block 10 sets a = 1, block 11 only touches another account, block 12 sets a = 2 and
+        // b = 3
+        let bundle = BundleState::new(
+            [
+                (
+                    address,
+                    None,
+                    Some(AccountInfo::default()),
+                    [(slot_a, (U256::ZERO, U256::from(2))), (slot_b, (U256::ZERO, U256::from(3)))]
+                        .into_iter()
+                        .collect(),
+                ),
+                (other, None, Some(AccountInfo::default()), Default::default()),
+            ],
+            vec![
+                vec![(address, Some(None), vec![(slot_a, U256::ZERO)])],
+                vec![(other, Some(None), vec![])],
+                vec![(address, None, vec![(slot_a, U256::from(1)), (slot_b, U256::ZERO)])],
+            ],
+            vec![],
+        );
+        let outcome = ExecutionOutcome::<()> { bundle, first_block: 10, ..Default::default() };
+
+        let changes = storage_changes_by_block(&outcome, address);
+        assert_eq!(
+            changes,
+            vec![
+                (10, HashMap::from_iter([(B256::from(slot_a), B256::from(U256::from(1)))])),
+                (
+                    12,
+                    HashMap::from_iter([
+                        (B256::from(slot_a), B256::from(U256::from(2))),
+                        (B256::from(slot_b), B256::from(U256::from(3))),
+                    ])
+                ),
+            ]
+        );
+
+        assert!(storage_changes_by_block(&outcome, Address::with_last_byte(3)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn storage_changes_subscription() {
+        let address = Address::with_last_byte(1);
+        let slot = U256::from(1);
+
+        let subscriptions = TestCanonStateSubscriptions::default();
+        let module = storage_changes_module(subscriptions.clone(), address, None);
+        let mut sub = module.subscribe("subscribe_storage", rpc_params![], 16).await.unwrap();
+
+        subscriptions.add_next_commit(slot_change(address, slot, U256::ZERO, U256::from(1)));
+        // blocks that don't touch the account's storage are not notified
+        subscriptions.add_next_commit(slot_change(
+            Address::with_last_byte(2),
+            slot,
+            U256::ZERO,
+            U256::from(5),
+        ));
+        subscriptions.add_next_commit(slot_change(address, slot, U256::from(1), U256::from(2)));
+
+        // each notification yields exactly one item for its single block
+        for value in [1, 2] {
+            let (changes, _) = sub.next::<HashMap<B256, B256>>().await.unwrap().unwrap();
+            assert_eq!(
+                changes,
+                HashMap::from_iter([(B256::from(slot), B256::from(U256::from(value)))])
+            );
+        }
+
+        // the block that set the slot to 2 is replaced by one that only changes another slot
+        let other_slot = U256::from(2);
+        subscriptions.add_next_reorg(
+            slot_change(address, slot, U256::from(1), U256::from(2)),
+            slot_change(address, other_slot, U256::ZERO, U256::from(7)),
+        );
+        for (slot, value) in [(slot, 1), (other_slot, 7)] {
+            let (changes, _) = sub.next::<HashMap<B256, B256>>().await.unwrap().unwrap();
+            assert_eq!(
+                changes,
+                HashMap::from_iter([(B256::from(slot), B256::from(U256::from(value)))])
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn storage_changes_subscription_closes_slow_subscriber() {
+        let address = Address::with_last_byte(1);
+        let slot = U256::from(1);
+
+        let subscriptions = TestCanonStateSubscriptions::default();
+        let module =
+            storage_changes_module(subscriptions.clone(), address, Some(Duration::from_millis(10)));
+        // room for a single notification, which is only read once the subscription was closed
+        let request = r#"{"jsonrpc":"2.0","id":1,"method":"subscribe_storage","params":[]}"#;
+        let (_, mut notifications) = module.raw_json_request(request, 1).await.unwrap();
+
+        for value in 1..=3u64 {
+            subscriptions.add_next_commit(slot_change(
+                address,
+                slot,
+                U256::from(value - 1),
+                U256::from(value),
+            ));
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let item = notifications.recv().await.unwrap();
+        assert!(item.contains(r#""result""#), "{item}");
+        let close = notifications.recv().await.unwrap();
+        assert!(close.contains(r#""error""#), "{close}");
+        assert!(close.contains("subscription closed"), "{close}");
+    }
+}